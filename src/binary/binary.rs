@@ -43,6 +43,11 @@ impl <T> BinaryNode<T> {
         &self.value
     }
 
+    /// Gets a mutable reference to the value behind the node.
+    pub fn value_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+
     /// Sets the value of the node to the given data.
     /// Returns the data that was there previously.
     pub fn set_value(&mut self, data: T) -> T {
@@ -54,6 +59,11 @@ impl <T> BinaryNode<T> {
         &self.children
     }
 
+    /// Gets a mutable reference to the children of this node in the tree.
+    pub fn children_mut(&mut self) -> &mut Children<T> {
+        &mut self.children
+    }
+
     /// Gets a unsafe mutable reference to the parent of the node.
     ///
     /// # Safety
@@ -75,6 +85,134 @@ impl <T> BinaryNode<T> {
         };
         mem::replace(child, Some(node))
     }
+
+    /// Removes the left/right child (if any) of this node, unlinking it and
+    /// returning the detached subtree. The detached node's `parent` pointer
+    /// is cleared.
+    pub fn remove_child(&mut self, dir: Dir) -> Child<T> {
+        let child = match dir {
+            Dir::Left  => &mut self.children.0,
+            Dir::Right => &mut self.children.1
+        };
+        let mut removed = mem::replace(child, None);
+        if let Some(ref mut node) = removed {
+            node.parent = null_mut();
+        }
+        removed
+    }
+
+    /// Sets this node's parent pointer directly.
+    ///
+    /// # Safety
+    /// Does not check that `parent` is a valid pointer, nor that this node
+    /// is actually reachable from it.
+    pub(crate) unsafe fn set_parent(&mut self, parent: *mut BinaryNode<T>) {
+        self.parent = parent;
+    }
+
+    /// Points this node's children's `parent` pointers back at this node.
+    ///
+    /// A node's address can change when it is moved by value (e.g. out of
+    /// one `Box`/`UnsafeCell` and into another), which leaves its children's
+    /// `parent` pointers dangling even though the tree shape is unchanged.
+    /// Call this after such a move, once the node is settled at its new
+    /// address, to restore the invariant.
+    pub(crate) fn reparent_children(&mut self) {
+        let self_ptr = self as *mut _;
+        unsafe {
+            if let Some(ref mut child) = self.children.0 {
+                child.set_parent(self_ptr);
+            }
+            if let Some(ref mut child) = self.children.1 {
+                child.set_parent(self_ptr);
+            }
+        }
+    }
+
+    /// Gets a raw pointer to the left child, or a null pointer if there is none.
+    pub(crate) fn left_ptr(&self) -> *mut BinaryNode<T> {
+        match self.children.0 {
+            Some(ref child) => &**child as *const _ as *mut _,
+            None => null_mut()
+        }
+    }
+
+    /// Gets a raw pointer to the right child, or a null pointer if there is none.
+    pub(crate) fn right_ptr(&self) -> *mut BinaryNode<T> {
+        match self.children.1 {
+            Some(ref child) => &**child as *const _ as *mut _,
+            None => null_mut()
+        }
+    }
+
+    /// Consumes the node, returning its children and value.
+    pub(crate) fn into_parts(self) -> (Children<T>, T) {
+        (self.children, self.value)
+    }
+}
+
+/// Walks to the leftmost descendant of `node`, or `node` itself if it has no
+/// left child.
+pub(crate) unsafe fn leftmost<T>(mut node: *mut BinaryNode<T>) -> *mut BinaryNode<T> {
+    loop {
+        let left = (*node).left_ptr();
+        if left == null_mut() {
+            return node;
+        }
+        node = left;
+    }
+}
+
+/// Walks to the rightmost descendant of `node`, or `node` itself if it has no
+/// right child.
+pub(crate) unsafe fn rightmost<T>(mut node: *mut BinaryNode<T>) -> *mut BinaryNode<T> {
+    loop {
+        let right = (*node).right_ptr();
+        if right == null_mut() {
+            return node;
+        }
+        node = right;
+    }
+}
+
+/// Finds the in-order successor of `node`, or a null pointer if `node` is the
+/// last node in order.
+pub(crate) unsafe fn successor<T>(node: *mut BinaryNode<T>) -> *mut BinaryNode<T> {
+    let right = (*node).right_ptr();
+    if right != null_mut() {
+        return leftmost(right);
+    }
+    let mut child = node;
+    loop {
+        let parent = (*child).parent();
+        if parent == null_mut() {
+            return null_mut();
+        }
+        if (*parent).left_ptr() == child {
+            return parent;
+        }
+        child = parent;
+    }
+}
+
+/// Finds the in-order predecessor of `node`, or a null pointer if `node` is
+/// the first node in order.
+pub(crate) unsafe fn predecessor<T>(node: *mut BinaryNode<T>) -> *mut BinaryNode<T> {
+    let left = (*node).left_ptr();
+    if left != null_mut() {
+        return rightmost(left);
+    }
+    let mut child = node;
+    loop {
+        let parent = (*child).parent();
+        if parent == null_mut() {
+            return null_mut();
+        }
+        if (*parent).right_ptr() == child {
+            return parent;
+        }
+        child = parent;
+    }
 }
 
 impl <T> BinaryTree<T> {
@@ -109,6 +247,32 @@ impl <T> BinaryTree<T> {
             (self.root.get()).as_mut().expect("Binary tree had no root node")
         }
     }
+
+    /// Consumes the tree, returning its root node.
+    pub(crate) fn into_root(self) -> BinaryNode<T> {
+        self.root.into_inner()
+    }
+
+    /// Points the root's children's `parent` pointers back at the root's
+    /// current address.
+    ///
+    /// Unlike its children, which each live in their own stable `Box`
+    /// allocation, the root is stored inline in `self.root`, so its address
+    /// changes every time the `BinaryTree` itself is moved by value (e.g.
+    /// returned from `BinaryViewMut::prune`) — which would otherwise leave
+    /// the root's children pointing at a stale address. Call this once the
+    /// tree is settled at the address a view/iterator will be built from;
+    /// it's idempotent, so calling it on an already-correct tree is harmless.
+    pub(crate) fn settle(&self) {
+        unsafe { (&mut *self.root.get()).reparent_children() }
+    }
+
+    /// Wraps an already-detached node (with no parent) up as a freestanding tree.
+    pub(crate) fn from_node(node: BinaryNode<T>) -> Self {
+        BinaryTree {
+            root: UnsafeCell::new(node)
+        }
+    }
 }
 
 
@@ -0,0 +1,316 @@
+use std::ptr::null_mut;
+use std::marker::PhantomData;
+use std::mem;
+
+use super::binary::{self, BinaryNode, BinaryTree};
+
+/// The order in which a tree's nodes are visited by `BinaryTree::traverse`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    /// Visit a node before its children.
+    PreOrder,
+    /// Visit the left subtree, then the node, then the right subtree.
+    InOrder,
+    /// Visit a node after its children.
+    PostOrder
+}
+
+/// An iterator over `&T`, visiting every value in a `BinaryTree` in order.
+///
+/// This is created by the `iter` method on `BinaryTree`.
+#[derive(Debug)]
+pub struct Iter<'tree, T: 'tree> {
+    next: *mut BinaryNode<T>,
+    data: PhantomData<&'tree T>
+}
+
+impl <'tree, T: 'tree> Iter<'tree, T> {
+    pub(crate) fn new(tree: &'tree BinaryTree<T>) -> Self {
+        tree.settle();
+        let root = unsafe { tree.as_ptr().get() };
+        Iter {
+            next: unsafe { binary::leftmost(root) },
+            data: PhantomData
+        }
+    }
+}
+
+impl <'tree, T: 'tree> Iterator for Iter<'tree, T> {
+    type Item = &'tree T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == null_mut() {
+            return None;
+        }
+        unsafe {
+            let node = &*self.next;
+            self.next = binary::successor(self.next);
+            Some(node.value())
+        }
+    }
+}
+
+/// An iterator over `&mut T`, visiting every value in a `BinaryTree` in order.
+///
+/// This is created by the `iter_mut` method on `BinaryTree`.
+#[derive(Debug)]
+pub struct IterMut<'tree, T: 'tree> {
+    next: *mut BinaryNode<T>,
+    data: PhantomData<&'tree mut T>
+}
+
+impl <'tree, T: 'tree> IterMut<'tree, T> {
+    pub(crate) fn new(tree: &'tree mut BinaryTree<T>) -> Self {
+        tree.settle();
+        let root = unsafe { tree.as_ptr().get() };
+        IterMut {
+            next: unsafe { binary::leftmost(root) },
+            data: PhantomData
+        }
+    }
+}
+
+impl <'tree, T: 'tree> Iterator for IterMut<'tree, T> {
+    type Item = &'tree mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == null_mut() {
+            return None;
+        }
+        unsafe {
+            let node = &mut *self.next;
+            self.next = binary::successor(self.next);
+            Some(node.value_mut())
+        }
+    }
+}
+
+/// An iterator that moves every value out of a `BinaryTree` in order.
+///
+/// This is created by the `IntoIterator` implementation on `BinaryTree`.
+///
+/// Nodes on the current left spine are held, owned, on `stack`; each is
+/// popped and torn down (freeing its `Box<BinaryNode<T>>`) as it is yielded,
+/// and its right child's left spine is then pushed in its place. This keeps
+/// the iterator lazy and bounds the extra space to the tree's height, rather
+/// than its size.
+#[derive(Debug)]
+pub struct IntoIter<T> {
+    stack: Vec<Box<BinaryNode<T>>>
+}
+
+impl <T> IntoIter<T> {
+    pub(crate) fn new(tree: BinaryTree<T>) -> Self {
+        let mut stack = Vec::new();
+        push_left_spine(Some(Box::new(tree.into_root())), &mut stack);
+        IntoIter { stack: stack }
+    }
+}
+
+impl <T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let mut node = self.stack.pop()?;
+        let right = mem::replace(&mut node.children_mut().1, None);
+        push_left_spine(right, &mut self.stack);
+        let (_, value) = node.into_parts();
+        Some(value)
+    }
+}
+
+impl <T> Drop for IntoIter<T> {
+    /// Tears down whatever is left on `stack` iteratively.
+    ///
+    /// If the iterator is dropped before being fully consumed, the nodes
+    /// still on `stack` may have entire un-visited subtrees hanging off
+    /// their right children. Letting those drop normally would recurse once
+    /// per node via `BinaryNode`'s (derived, absent) `Drop`, which is
+    /// exactly the stack-depth problem this iterator exists to avoid; so
+    /// instead, flatten them onto a work list and drop each node only once
+    /// its own children have already been detached.
+    fn drop(&mut self) {
+        let mut work = mem::replace(&mut self.stack, Vec::new());
+        while let Some(mut node) = work.pop() {
+            let left = mem::replace(&mut node.children_mut().0, None);
+            let right = mem::replace(&mut node.children_mut().1, None);
+            if let Some(left) = left { work.push(left); }
+            if let Some(right) = right { work.push(right); }
+        }
+    }
+}
+
+/// Pushes `node` and the chain of its left children onto `stack`, clearing
+/// each pushed node's own left child as it goes. Iterative, so the stack
+/// depth this builds up (bounded by the tree's height) lives on the heap
+/// rather than the call stack.
+fn push_left_spine<T>(mut node: Option<Box<BinaryNode<T>>>, stack: &mut Vec<Box<BinaryNode<T>>>) {
+    while let Some(mut n) = node {
+        node = mem::replace(&mut n.children_mut().0, None);
+        stack.push(n);
+    }
+}
+
+impl <T> IntoIterator for BinaryTree<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter::new(self)
+    }
+}
+
+impl <T> BinaryTree<T> {
+    /// Returns an iterator that visits every value in the tree, in order,
+    /// by reference.
+    pub fn iter(&self) -> Iter<T> {
+        Iter::new(self)
+    }
+
+    /// Returns an iterator that visits every value in the tree, in order,
+    /// by mutable reference.
+    pub fn iter_mut(&mut self) -> IterMut<T> {
+        IterMut::new(self)
+    }
+
+    /// Visits every value in the tree in the given `Order`, collecting
+    /// references to them into a `Vec`.
+    ///
+    /// Traversal is iterative (an explicit `Vec`-backed stack rather than
+    /// recursion), so a deep, unbalanced tree cannot overflow the call stack.
+    pub fn traverse(&self, order: Order) -> Vec<&T> {
+        match order {
+            Order::PreOrder => traverse_pre_order(self.root()),
+            Order::InOrder => traverse_in_order(self.root()),
+            Order::PostOrder => traverse_post_order(self.root())
+        }
+    }
+}
+
+fn child_ref<T>(child: &Option<Box<BinaryNode<T>>>) -> Option<&BinaryNode<T>> {
+    match *child {
+        Some(ref node) => Some(&**node),
+        None => None
+    }
+}
+
+fn traverse_pre_order<T>(root: &BinaryNode<T>) -> Vec<&T> {
+    let mut out = Vec::new();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        out.push(node.value());
+        let &(ref left, ref right) = node.children();
+        if let Some(right) = child_ref(right) { stack.push(right); }
+        if let Some(left) = child_ref(left) { stack.push(left); }
+    }
+    out
+}
+
+fn traverse_in_order<T>(root: &BinaryNode<T>) -> Vec<&T> {
+    let mut out = Vec::new();
+    let mut stack = Vec::new();
+    let mut current = Some(root);
+    while current.is_some() || !stack.is_empty() {
+        while let Some(node) = current {
+            stack.push(node);
+            current = child_ref(&node.children().0);
+        }
+        let node = stack.pop().expect("in-order traversal: stack unexpectedly empty");
+        out.push(node.value());
+        current = child_ref(&node.children().1);
+    }
+    out
+}
+
+fn traverse_post_order<T>(root: &BinaryNode<T>) -> Vec<&T> {
+    let mut to_visit = vec![root];
+    let mut visited = Vec::new();
+    while let Some(node) = to_visit.pop() {
+        visited.push(node);
+        let &(ref left, ref right) = node.children();
+        if let Some(left) = child_ref(left) { to_visit.push(left); }
+        if let Some(right) = child_ref(right) { to_visit.push(right); }
+    }
+    visited.into_iter().rev().map(|node| node.value()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BinaryTree, Order};
+    use super::super::binary::Dir;
+
+    /// Builds a small, complete (balanced) tree:
+    ///
+    /// ```text
+    ///         4
+    ///        / \
+    ///       2   6
+    ///      / \ / \
+    ///     1  3 5  7
+    /// ```
+    fn sample_tree() -> BinaryTree<i32> {
+        let mut tree = BinaryTree::new(4);
+        {
+            let root = tree.root_mut();
+            root.add_child(Dir::Left, 2);
+            root.add_child(Dir::Right, 6);
+        }
+        {
+            let left = tree.root_mut().children_mut().0.as_mut().unwrap();
+            left.add_child(Dir::Left, 1);
+            left.add_child(Dir::Right, 3);
+        }
+        {
+            let right = tree.root_mut().children_mut().1.as_mut().unwrap();
+            right.add_child(Dir::Left, 5);
+            right.add_child(Dir::Right, 7);
+        }
+        tree
+    }
+
+    #[test]
+    fn iter_visits_in_order() {
+        let tree = sample_tree();
+        let values: Vec<i32> = tree.iter().cloned().collect();
+        assert_eq!(values, vec![1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn iter_mut_can_update_values_in_place() {
+        let mut tree = sample_tree();
+        for value in tree.iter_mut() {
+            *value *= 10;
+        }
+        let values: Vec<i32> = tree.iter().cloned().collect();
+        assert_eq!(values, vec![10, 20, 30, 40, 50, 60, 70]);
+    }
+
+    #[test]
+    fn into_iter_consumes_in_order() {
+        let tree = sample_tree();
+        let values: Vec<i32> = tree.into_iter().collect();
+        assert_eq!(values, vec![1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn into_iter_partial_consumption_drops_remainder_without_overflow() {
+        let tree = sample_tree();
+        let mut iter = tree.into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        // The remaining nodes (3, 4, 5, 6, 7) are torn down when `iter` drops here.
+    }
+
+    #[test]
+    fn traverse_visits_each_order_correctly() {
+        let tree = sample_tree();
+        let pre: Vec<i32> = tree.traverse(Order::PreOrder).into_iter().cloned().collect();
+        assert_eq!(pre, vec![4, 2, 1, 3, 6, 5, 7]);
+
+        let inorder: Vec<i32> = tree.traverse(Order::InOrder).into_iter().cloned().collect();
+        assert_eq!(inorder, vec![1, 2, 3, 4, 5, 6, 7]);
+
+        let post: Vec<i32> = tree.traverse(Order::PostOrder).into_iter().cloned().collect();
+        assert_eq!(post, vec![1, 3, 2, 5, 7, 6, 4]);
+    }
+}
@@ -1,8 +1,7 @@
 mod view;
 mod binary;
+mod iter;
 
 pub use self::binary::{BinaryTree, BinaryNode, Dir};
 pub use self::view::{BinaryView, BinaryViewMut};
-
-#[cfg(test)]
-mod tests;
+pub use self::iter::{Iter, IterMut, IntoIter, Order};
@@ -1,8 +1,9 @@
 use std::ptr::null_mut;
 use std::ops::Deref;
 use std::marker::PhantomData;
+use std::mem;
 
-use super::binary::{BinaryNode, BinaryTree, Dir};
+use super::binary::{self, BinaryNode, BinaryTree, Child, Dir};
 
 /// Base view struct. This implements all the main methods used
 /// to traverse the binary tree.
@@ -59,10 +60,57 @@ impl <'tree, T: 'tree> BinaryViewInner<'tree, T> {
             }
         }
     }
+
+    /// Moves the view to the in-order successor of the focused node.
+    ///
+    /// If the node has a right child, this is the leftmost descendant of
+    /// that child. Otherwise it is the nearest ancestor for which the
+    /// focused node lies in the left subtree.
+    ///
+    /// If there is no such node (the view is already at the last node in
+    /// order), an `Err` with the view in its original place is returned.
+    pub fn next(mut self) -> Result<Self, Self> {
+        if self.node == null_mut() {
+            panic!("View pointed to an invalid tree");
+        }
+        unsafe {
+            let succ = binary::successor(self.node);
+            if succ == null_mut() {
+                Err(self)
+            } else {
+                self.node = succ;
+                Ok(self)
+            }
+        }
+    }
+
+    /// Moves the view to the in-order predecessor of the focused node.
+    ///
+    /// If the node has a left child, this is the rightmost descendant of
+    /// that child. Otherwise it is the nearest ancestor for which the
+    /// focused node lies in the right subtree.
+    ///
+    /// If there is no such node (the view is already at the first node in
+    /// order), an `Err` with the view in its original place is returned.
+    pub fn prev(mut self) -> Result<Self, Self> {
+        if self.node == null_mut() {
+            panic!("View pointed to an invalid tree");
+        }
+        unsafe {
+            let pred = binary::predecessor(self.node);
+            if pred == null_mut() {
+                Err(self)
+            } else {
+                self.node = pred;
+                Ok(self)
+            }
+        }
+    }
 }
 
 impl <'tree, T: 'tree> BinaryView<'tree, T> {
     pub fn new(tree: &'tree BinaryTree<T>) -> Self {
+        tree.settle();
         BinaryView(BinaryViewInner {
             node: unsafe { tree.as_ptr().get() },
             data: PhantomData::default()
@@ -144,14 +192,237 @@ impl <'tree, T: 'tree> BinaryView<'tree, T> {
             }
         }
     }
+
+    /// Moves the view to the in-order successor of the focused node.
+    ///
+    /// This is a wrapper, please see the method on `BinaryViewInner` for more details
+    pub fn next(mut self) -> Result<Self, Self> {
+        match self.0.next() {
+            Ok(node) => {
+                self.0 = node;
+                Ok(self)
+            },
+            Err(node) => {
+                self.0 = node;
+                Err(self)
+            }
+        }
+    }
+
+    /// Moves the view to the in-order predecessor of the focused node.
+    ///
+    /// This is a wrapper, please see the method on `BinaryViewInner` for more details
+    pub fn prev(mut self) -> Result<Self, Self> {
+        match self.0.prev() {
+            Ok(node) => {
+                self.0 = node;
+                Ok(self)
+            },
+            Err(node) => {
+                self.0 = node;
+                Err(self)
+            }
+        }
+    }
 }
 
 impl <'tree, T: 'tree> BinaryViewMut<'tree, T> {
     pub fn new(tree: &'tree mut BinaryTree<T>) -> Self {
+        tree.settle();
         BinaryViewMut(BinaryViewInner {
             node: unsafe { tree.as_ptr().get() },
             data: PhantomData::default()
         })
     }
+
+    /// Moves the view to the in-order successor of the focused node.
+    ///
+    /// This is a wrapper, please see the method on `BinaryViewInner` for more details
+    pub fn next(mut self) -> Result<Self, Self> {
+        match self.0.next() {
+            Ok(node) => {
+                self.0 = node;
+                Ok(self)
+            },
+            Err(node) => {
+                self.0 = node;
+                Err(self)
+            }
+        }
+    }
+
+    /// Moves the view to the in-order predecessor of the focused node.
+    ///
+    /// This is a wrapper, please see the method on `BinaryViewInner` for more details
+    pub fn prev(mut self) -> Result<Self, Self> {
+        match self.0.prev() {
+            Ok(node) => {
+                self.0 = node;
+                Ok(self)
+            },
+            Err(node) => {
+                self.0 = node;
+                Err(self)
+            }
+        }
+    }
+
+    /// Detaches the focused node, and everything below it, into a
+    /// freestanding `BinaryTree`.
+    ///
+    /// Returns the detached tree along with a view left focused on the
+    /// (former) parent of the pruned node.
+    ///
+    /// Note that the returned tree's root has not yet settled at its final
+    /// address (it is about to be moved out by value), so its children's
+    /// `parent` pointers are not fixed up here; `BinaryTree` re-establishes
+    /// them itself the next time the tree is used to build a view or
+    /// iterator, once it has settled.
+    ///
+    /// # Panics
+    /// Panics if the view is focused on the root of the tree, which has no
+    /// parent to detach it from.
+    pub fn prune(self) -> (BinaryTree<T>, BinaryViewMut<'tree, T>) {
+        if self.0.node == null_mut() {
+            panic!("View pointed to an invalid tree");
+        }
+        unsafe {
+            let node_ptr = self.0.node;
+            let parent_ptr = (&*node_ptr).parent();
+            if parent_ptr == null_mut() {
+                panic!("Cannot prune the root of a tree");
+            }
+            let dir = if (&*parent_ptr).left_ptr() == node_ptr {
+                Dir::Left
+            } else {
+                Dir::Right
+            };
+            let detached = (&mut *parent_ptr).remove_child(dir)
+                .expect("prune: focused node missing from its parent");
+            let tree = BinaryTree::from_node(*detached);
+            let parent_view = BinaryViewMut(BinaryViewInner {
+                node: parent_ptr,
+                data: PhantomData
+            });
+            (tree, parent_view)
+        }
+    }
+
+    /// Reattaches a previously detached `subtree` as the left/right child of
+    /// the focused node, fixing up the new root's `parent` pointer.
+    ///
+    /// The previous child in that direction (if any) is returned.
+    pub fn graft(&mut self, dir: Dir, subtree: BinaryTree<T>) -> Child<T> {
+        if self.0.node == null_mut() {
+            panic!("View pointed to an invalid tree");
+        }
+        unsafe {
+            let mut new_root = Box::new(subtree.into_root());
+            new_root.set_parent(self.0.node);
+            new_root.reparent_children();
+            let node = &mut *self.0.node;
+            let slot = match dir {
+                Dir::Left  => &mut node.children_mut().0,
+                Dir::Right => &mut node.children_mut().1
+            };
+            mem::replace(slot, Some(new_root))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BinaryViewMut, BinaryViewInner};
+    use super::super::binary::{BinaryTree, Dir};
+    use std::marker::PhantomData;
+
+    /// Builds:
+    ///
+    /// ```text
+    ///       1
+    ///      / \
+    ///     2   3
+    ///    / \
+    ///   4   5
+    /// ```
+    fn sample_tree() -> BinaryTree<i32> {
+        let mut tree = BinaryTree::new(1);
+        {
+            let root = tree.root_mut();
+            root.add_child(Dir::Left, 2);
+            root.add_child(Dir::Right, 3);
+        }
+        {
+            let left = tree.root_mut().children_mut().0.as_mut().unwrap();
+            left.add_child(Dir::Left, 4);
+            left.add_child(Dir::Right, 5);
+        }
+        tree
+    }
+
+    /// Builds a `BinaryViewMut` focused directly on `tree`'s left child,
+    /// bypassing the public API (`BinaryViewMut` has no `descend`, so from
+    /// the root it would only reach that node by an in-order walk).
+    fn view_at_left_child(tree: &mut BinaryTree<i32>) -> BinaryViewMut<i32> {
+        // Settle first: the root may have moved by value (e.g. returned out
+        // of `sample_tree`) since it was last settled, and the left child's
+        // `parent` pointer needs to be current before `prune` reads it.
+        tree.settle();
+        let node = tree.root_mut().children_mut().0.as_mut().unwrap().as_mut() as *mut _;
+        BinaryViewMut(BinaryViewInner { node: node, data: PhantomData })
+    }
+
+    #[test]
+    fn prune_detaches_the_subtree_and_leaves_the_parent_without_that_child() {
+        let mut tree = sample_tree();
+        let view = view_at_left_child(&mut tree);
+        let (pruned, parent) = view.prune();
+
+        assert_eq!(pruned.root().value(), &2);
+        let values: Vec<i32> = pruned.iter().cloned().collect();
+        assert_eq!(values, vec![4, 2, 5]);
+
+        let parent_node = unsafe { &*parent.0.node };
+        assert_eq!(parent_node.value(), &1);
+        let &(ref left, ref right) = parent_node.children();
+        assert!(left.is_none());
+        assert_eq!(right.as_ref().map(|c| *c.value()), Some(3));
+    }
+
+    #[test]
+    fn pruned_subtree_navigates_correctly_once_settled() {
+        let mut tree = sample_tree();
+        let view = view_at_left_child(&mut tree);
+        let (pruned, _parent) = view.prune();
+
+        // `settle()` is what `BinaryView[Mut]::new`/`Iter[Mut]::new` call
+        // before handing out a pointer into the tree; call it directly here
+        // to fix up the (now stable) root's children's `parent` pointers
+        // before navigating, so climbing back up from a child lands on the
+        // settled root rather than the stale, pre-move address.
+        pruned.settle();
+        let right_child = unsafe {
+            let root = &mut *pruned.as_ptr().get();
+            root.children_mut().1.as_mut().unwrap().as_mut() as *mut _
+        };
+        let sub_view = BinaryViewInner { node: right_child, data: PhantomData };
+        let sub_view = sub_view.climb().ok().expect("should climb back to the settled root");
+        let node = unsafe { &*sub_view.node };
+        assert_eq!(node.value(), &2);
+    }
+
+    #[test]
+    fn graft_reattaches_a_subtree_and_returns_the_displaced_child() {
+        let mut tree = sample_tree();
+        let view = view_at_left_child(&mut tree);
+        let (subtree, _) = view.prune();
+
+        let mut root_view = BinaryViewMut::new(&mut tree);
+        let displaced = root_view.graft(Dir::Right, subtree);
+        assert_eq!(displaced.unwrap().value(), &3);
+
+        let values: Vec<i32> = tree.iter().cloned().collect();
+        assert_eq!(values, vec![1, 4, 2, 5]);
+    }
 }
 
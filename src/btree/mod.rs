@@ -0,0 +1,122 @@
+mod node;
+
+use std::mem;
+
+use self::node::{InternalNode, LeafNode, Node};
+
+/// The maximum number of keys (and children, for internal nodes minus one)
+/// held by a single node.
+const MAX_N: usize = 8;
+
+/// An ordered map backed by a B-tree with a fixed fanout of `MAX_N`.
+///
+/// Unlike `BinaryTree`, which allocates one node per value, each node here
+/// holds up to `MAX_N` key/value pairs in a flat array, giving much better
+/// cache locality and far fewer allocations.
+pub struct BTreeMap<K: Ord, V> {
+    root: Box<Node<K, V>>,
+    len: usize
+}
+
+impl <K: Ord, V> BTreeMap<K, V> {
+    /// Constructs a new, empty B-tree map.
+    pub fn new() -> Self {
+        BTreeMap {
+            root: Box::new(Node::Leaf(LeafNode::new())),
+            len: 0
+        }
+    }
+
+    /// Gets the number of key/value pairs stored in the map.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the map has no key/value pairs.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Gets a reference to the value associated with `key`, if it is present.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.root.get(key)
+    }
+
+    /// Inserts `value` under `key`, returning the previous value for `key`
+    /// if one was present.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if self.root.is_full() {
+            let old_root = mem::replace(&mut self.root, Box::new(Node::Leaf(LeafNode::new())));
+            let mut new_root = InternalNode::new();
+            new_root.set_first_child(old_root);
+            self.root = Box::new(Node::Internal(new_root));
+            if let Node::Internal(ref mut internal) = *self.root {
+                internal.split_child(0);
+            }
+        }
+        let old = self.root.insert_nonfull(key, value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BTreeMap;
+
+    #[test]
+    fn insert_get_roundtrip() {
+        let mut map = BTreeMap::new();
+        assert_eq!(map.insert(5, "five"), None);
+        assert_eq!(map.insert(2, "two"), None);
+        assert_eq!(map.insert(8, "eight"), None);
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.get(&5), Some(&"five"));
+        assert_eq!(map.get(&2), Some(&"two"));
+        assert_eq!(map.get(&8), Some(&"eight"));
+        assert_eq!(map.get(&9), None);
+    }
+
+    #[test]
+    fn insert_replaces_existing_value_without_growing_len() {
+        let mut map = BTreeMap::new();
+        map.insert(1, "a");
+        assert_eq!(map.insert(1, "b"), Some("a"));
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get(&1), Some(&"b"));
+    }
+
+    /// Inserts enough keys to force repeated node splits, then re-inserts
+    /// one of them. A node split promotes a median key into its parent; if
+    /// the promoted key happens to equal the key being (re-)inserted, it
+    /// must be treated as a replacement rather than a duplicate insert.
+    #[test]
+    fn insert_replaces_existing_value_across_a_node_split() {
+        let mut map = BTreeMap::new();
+        for key in 0..200 {
+            assert_eq!(map.insert(key, key), None);
+        }
+        assert_eq!(map.len(), 200);
+        for key in 0..200 {
+            assert_eq!(map.insert(key, key * 10), Some(key));
+        }
+        assert_eq!(map.len(), 200);
+        for key in 0..200 {
+            assert_eq!(map.get(&key), Some(&(key * 10)));
+        }
+    }
+
+    #[test]
+    fn get_finds_every_key_after_many_splits() {
+        let mut map = BTreeMap::new();
+        for key in 0..500 {
+            map.insert(key, key.to_string());
+        }
+        for key in 0..500 {
+            assert_eq!(map.get(&key), Some(&key.to_string()));
+        }
+        assert_eq!(map.get(&500), None);
+    }
+}
@@ -0,0 +1,267 @@
+use std::cmp::Ordering;
+use std::mem::MaybeUninit;
+use std::ptr;
+
+use super::MAX_N;
+
+/// A node holding up to `MAX_N` key/value pairs in a flat, populated-prefix
+/// array. This is also the header embedded in every `InternalNode`, so both
+/// kinds of node store their own keys and values the same way.
+pub(crate) struct LeafNode<K, V> {
+    keys: [MaybeUninit<K>; MAX_N],
+    values: [MaybeUninit<V>; MAX_N],
+    count: usize
+}
+
+/// An internal node: a `LeafNode` header for its own keys/values, plus
+/// `MAX_N + 1` children, one more than it has keys.
+pub(crate) struct InternalNode<K, V> {
+    header: LeafNode<K, V>,
+    children: [MaybeUninit<Box<Node<K, V>>>; MAX_N + 1]
+}
+
+/// A `BTreeMap` node: either a leaf or an internal node with children.
+pub(crate) enum Node<K, V> {
+    Leaf(LeafNode<K, V>),
+    Internal(InternalNode<K, V>)
+}
+
+impl <K, V> LeafNode<K, V> {
+    pub(crate) fn new() -> Self {
+        LeafNode {
+            keys: unsafe { MaybeUninit::uninit().assume_init() },
+            values: unsafe { MaybeUninit::uninit().assume_init() },
+            count: 0
+        }
+    }
+
+    pub(crate) fn is_full(&self) -> bool {
+        self.count == MAX_N
+    }
+
+    pub(crate) fn key_at(&self, i: usize) -> &K {
+        unsafe { &*self.keys[i].as_ptr() }
+    }
+
+    pub(crate) fn value_at(&self, i: usize) -> &V {
+        unsafe { &*self.values[i].as_ptr() }
+    }
+
+    pub(crate) fn value_at_mut(&mut self, i: usize) -> &mut V {
+        unsafe { &mut *self.values[i].as_mut_ptr() }
+    }
+
+    /// Binary searches the populated prefix for `key`, returning `Ok(i)` if
+    /// it is already present at `i`, or `Err(i)` for where it belongs.
+    pub(crate) fn search(&self, key: &K) -> Result<usize, usize> where K: Ord {
+        let mut lo = 0;
+        let mut hi = self.count;
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match key.cmp(self.key_at(mid)) {
+                Ordering::Equal => return Ok(mid),
+                Ordering::Less => hi = mid,
+                Ordering::Greater => lo = mid + 1
+            }
+        }
+        Err(lo)
+    }
+
+    /// Inserts `key`/`value` at `idx`, shifting later entries right by one.
+    ///
+    /// The node must not already be full.
+    pub(crate) fn insert_at(&mut self, idx: usize, key: K, value: V) {
+        debug_assert!(self.count < MAX_N, "insert_at: leaf is full");
+        unsafe {
+            let key_ptr = self.keys.as_mut_ptr();
+            let val_ptr = self.values.as_mut_ptr();
+            ptr::copy(key_ptr.add(idx), key_ptr.add(idx + 1), self.count - idx);
+            ptr::copy(val_ptr.add(idx), val_ptr.add(idx + 1), self.count - idx);
+            ptr::write(key_ptr.add(idx), MaybeUninit::new(key));
+            ptr::write(val_ptr.add(idx), MaybeUninit::new(value));
+        }
+        self.count += 1;
+    }
+
+    /// Splits a full node in half, returning the promoted median key/value
+    /// and a new node holding the upper half.
+    pub(crate) fn split(&mut self) -> (K, V, LeafNode<K, V>) {
+        debug_assert_eq!(self.count, MAX_N, "split: leaf is not full");
+        let mid = MAX_N / 2;
+        let right_count = self.count - mid - 1;
+        let mut right = LeafNode::new();
+        unsafe {
+            let key_ptr = self.keys.as_mut_ptr();
+            let val_ptr = self.values.as_mut_ptr();
+            ptr::copy_nonoverlapping(key_ptr.add(mid + 1), right.keys.as_mut_ptr(), right_count);
+            ptr::copy_nonoverlapping(val_ptr.add(mid + 1), right.values.as_mut_ptr(), right_count);
+            let median_key = ptr::read(key_ptr.add(mid)).assume_init();
+            let median_value = ptr::read(val_ptr.add(mid)).assume_init();
+            right.count = right_count;
+            self.count = mid;
+            (median_key, median_value, right)
+        }
+    }
+}
+
+impl <K, V> Drop for LeafNode<K, V> {
+    fn drop(&mut self) {
+        unsafe {
+            for i in 0..self.count {
+                ptr::drop_in_place(self.keys[i].as_mut_ptr());
+                ptr::drop_in_place(self.values[i].as_mut_ptr());
+            }
+        }
+    }
+}
+
+impl <K, V> InternalNode<K, V> {
+    pub(crate) fn new() -> Self {
+        InternalNode {
+            header: LeafNode::new(),
+            children: unsafe { MaybeUninit::uninit().assume_init() }
+        }
+    }
+
+    pub(crate) fn header(&self) -> &LeafNode<K, V> {
+        &self.header
+    }
+
+    pub(crate) fn header_mut(&mut self) -> &mut LeafNode<K, V> {
+        &mut self.header
+    }
+
+    pub(crate) fn child_count(&self) -> usize {
+        self.header.count + 1
+    }
+
+    pub(crate) fn child_ref(&self, i: usize) -> &Node<K, V> {
+        unsafe { &*self.children[i].as_ptr() }
+    }
+
+    pub(crate) fn child_mut(&mut self, i: usize) -> &mut Node<K, V> {
+        unsafe { &mut *self.children[i].as_mut_ptr() }
+    }
+
+    /// Places `child` as the sole child of an otherwise empty node. Used to
+    /// seed a freshly grown root.
+    pub(crate) fn set_first_child(&mut self, child: Box<Node<K, V>>) {
+        debug_assert_eq!(self.header.count, 0);
+        self.children[0] = MaybeUninit::new(child);
+    }
+
+    /// Inserts `child` at `idx`, shifting the children at `idx..count` right
+    /// by one.
+    ///
+    /// `count` must be the child count *before* this insertion; callers that
+    /// have already grown `header.count` (e.g. `split_child`) must capture
+    /// it beforehand rather than re-deriving it from `child_count()`.
+    fn insert_child_at(&mut self, idx: usize, count: usize, child: Box<Node<K, V>>) {
+        unsafe {
+            let child_ptr = self.children.as_mut_ptr();
+            ptr::copy(child_ptr.add(idx), child_ptr.add(idx + 1), count - idx);
+            ptr::write(child_ptr.add(idx), MaybeUninit::new(child));
+        }
+    }
+
+    /// Splits the full child at `i`, promoting its median into this node at
+    /// `i` and inserting the new right sibling at `i + 1`.
+    pub(crate) fn split_child(&mut self, i: usize) {
+        let count = self.child_count();
+        let (median_key, median_value, right) = match *self.child_mut(i) {
+            Node::Leaf(ref mut leaf) => {
+                let (k, v, right_leaf) = leaf.split();
+                (k, v, Node::Leaf(right_leaf))
+            },
+            Node::Internal(ref mut internal) => {
+                let (k, v, right_internal) = internal.split();
+                (k, v, Node::Internal(right_internal))
+            }
+        };
+        self.header.insert_at(i, median_key, median_value);
+        self.insert_child_at(i + 1, count, Box::new(right));
+    }
+
+    /// Splits this full internal node in half, returning the promoted
+    /// median key/value and a new node holding the upper half, including
+    /// the children that moved with it.
+    fn split(&mut self) -> (K, V, InternalNode<K, V>) {
+        let mid = MAX_N / 2;
+        let count = self.child_count();
+        let (median_key, median_value, right_header) = self.header.split();
+        let mut right = InternalNode {
+            header: right_header,
+            children: unsafe { MaybeUninit::uninit().assume_init() }
+        };
+        let moved = count - (mid + 1);
+        unsafe {
+            let src = self.children.as_mut_ptr();
+            let dst = right.children.as_mut_ptr();
+            ptr::copy_nonoverlapping(src.add(mid + 1), dst, moved);
+        }
+        (median_key, median_value, right)
+    }
+}
+
+impl <K, V> Drop for InternalNode<K, V> {
+    fn drop(&mut self) {
+        unsafe {
+            for i in 0..self.child_count() {
+                ptr::drop_in_place(self.children[i].as_mut_ptr());
+            }
+        }
+    }
+}
+
+impl <K: Ord, V> Node<K, V> {
+    pub(crate) fn is_full(&self) -> bool {
+        match *self {
+            Node::Leaf(ref leaf) => leaf.is_full(),
+            Node::Internal(ref internal) => internal.header().is_full()
+        }
+    }
+
+    pub(crate) fn get(&self, key: &K) -> Option<&V> {
+        match *self {
+            Node::Leaf(ref leaf) => match leaf.search(key) {
+                Ok(i) => Some(leaf.value_at(i)),
+                Err(_) => None
+            },
+            Node::Internal(ref internal) => match internal.header().search(key) {
+                Ok(i) => Some(internal.header().value_at(i)),
+                Err(i) => internal.child_ref(i).get(key)
+            }
+        }
+    }
+
+    /// Inserts into a node that is known not to be full, returning the
+    /// previous value if `key` was already present.
+    pub(crate) fn insert_nonfull(&mut self, key: K, value: V) -> Option<V> {
+        match *self {
+            Node::Leaf(ref mut leaf) => match leaf.search(&key) {
+                Ok(i) => Some(::std::mem::replace(leaf.value_at_mut(i), value)),
+                Err(i) => {
+                    leaf.insert_at(i, key, value);
+                    None
+                }
+            },
+            Node::Internal(ref mut internal) => match internal.header().search(&key) {
+                Ok(i) => Some(::std::mem::replace(internal.header_mut().value_at_mut(i), value)),
+                Err(mut i) => {
+                    if internal.child_ref(i).is_full() {
+                        internal.split_child(i);
+                        match key.cmp(internal.header().key_at(i)) {
+                            Ordering::Equal => {
+                                let old = ::std::mem::replace(internal.header_mut().value_at_mut(i), value);
+                                return Some(old);
+                            },
+                            Ordering::Greater => i += 1,
+                            Ordering::Less => {}
+                        }
+                    }
+                    internal.child_mut(i).insert_nonfull(key, value)
+                }
+            }
+        }
+    }
+}
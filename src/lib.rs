@@ -8,6 +8,9 @@
     unused_import_braces, unused_qualifications
 )]
 mod binary;
+mod redblack;
+mod btree;
+mod slab;
 
 #[cfg(test)]
 mod tests {
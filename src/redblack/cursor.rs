@@ -0,0 +1,62 @@
+use std::ptr::null_mut;
+use std::marker::PhantomData;
+
+use super::node::{self, RbNode};
+
+/// A read-only cursor over a `RedBlackTree`'s nodes, in sorted order.
+///
+/// This is the `RedBlackTree` analogue of `BinaryView`: it is created
+/// focused on one node and consumed by `next`/`prev` to move to the
+/// in-order neighbor in either direction.
+#[derive(Debug)]
+pub struct Cursor<'tree, K: 'tree, V: 'tree> {
+    node: *mut RbNode<K, V>,
+    data: PhantomData<&'tree (K, V)>
+}
+
+impl <'tree, K: 'tree, V: 'tree> Cursor<'tree, K, V> {
+    pub(crate) fn new(node: *mut RbNode<K, V>) -> Self {
+        Cursor {
+            node: node,
+            data: PhantomData
+        }
+    }
+
+    /// Gets the key the cursor is currently focused on.
+    pub fn key(&self) -> &K {
+        unsafe { &(*self.node).key }
+    }
+
+    /// Gets the value the cursor is currently focused on.
+    pub fn value(&self) -> &V {
+        unsafe { &(*self.node).value }
+    }
+
+    /// Moves the cursor to the in-order successor of the focused node.
+    ///
+    /// If there is no such node, an `Err` with the cursor in its original
+    /// place is returned.
+    pub fn next(mut self) -> Result<Self, Self> {
+        let succ = unsafe { node::successor(self.node) };
+        if succ == null_mut() {
+            Err(self)
+        } else {
+            self.node = succ;
+            Ok(self)
+        }
+    }
+
+    /// Moves the cursor to the in-order predecessor of the focused node.
+    ///
+    /// If there is no such node, an `Err` with the cursor in its original
+    /// place is returned.
+    pub fn prev(mut self) -> Result<Self, Self> {
+        let pred = unsafe { node::predecessor(self.node) };
+        if pred == null_mut() {
+            Err(self)
+        } else {
+            self.node = pred;
+            Ok(self)
+        }
+    }
+}
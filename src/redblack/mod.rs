@@ -0,0 +1,551 @@
+mod node;
+mod cursor;
+
+use std::ptr::null_mut;
+use std::mem;
+use std::cmp::Ordering;
+
+use self::node::{Child, Color, RbNode, is_red, leftmost, rightmost};
+
+pub use self::cursor::Cursor;
+
+/// Which side of a node a child lives on, used while descending to find
+/// where a new key belongs.
+enum Dir {
+    Left,
+    Right
+}
+
+/// A self-balancing ordered map, backed by a red-black tree.
+///
+/// Like `BinaryTree`, nodes are linked with a parent pointer alongside their
+/// two children, but each node also carries a `Color` that `insert` and
+/// `remove` maintain to keep the tree balanced, guaranteeing O(log n)
+/// `insert`, `get`, and `remove`.
+#[derive(Debug)]
+pub struct RedBlackTree<K: Ord, V> {
+    root: Child<K, V>,
+    len: usize
+}
+
+impl <K: Ord, V> RedBlackTree<K, V> {
+    /// Constructs a new, empty red-black tree.
+    pub fn new() -> Self {
+        RedBlackTree {
+            root: None,
+            len: 0
+        }
+    }
+
+    /// Gets the number of key/value pairs stored in the tree.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the tree has no key/value pairs.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Gets a reference to the value associated with `key`, if it is present.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let mut current = self.root.as_ref();
+        while let Some(node) = current {
+            current = match key.cmp(&node.key) {
+                Ordering::Equal => return Some(&node.value),
+                Ordering::Less => node.children.0.as_ref(),
+                Ordering::Greater => node.children.1.as_ref()
+            };
+        }
+        None
+    }
+
+    /// Inserts `value` under `key`, returning the previous value for `key`
+    /// if one was present.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let mut parent: *mut RbNode<K, V> = null_mut();
+        let mut dir = Dir::Left;
+        let mut current = self.root_ptr();
+        unsafe {
+            while current != null_mut() {
+                match key.cmp(&(*current).key) {
+                    Ordering::Equal => {
+                        return Some(mem::replace(&mut (*current).value, value));
+                    },
+                    Ordering::Less => {
+                        parent = current;
+                        dir = Dir::Left;
+                        current = (*current).left_ptr();
+                    },
+                    Ordering::Greater => {
+                        parent = current;
+                        dir = Dir::Right;
+                        current = (*current).right_ptr();
+                    }
+                }
+            }
+        }
+
+        let mut new_node = Box::new(RbNode::new(key, value));
+        new_node.parent = parent;
+        let new_ptr: *mut RbNode<K, V> = &mut *new_node;
+
+        if parent == null_mut() {
+            self.root = Some(new_node);
+        } else {
+            unsafe {
+                match dir {
+                    Dir::Left => (*parent).children.0 = Some(new_node),
+                    Dir::Right => (*parent).children.1 = Some(new_node)
+                }
+            }
+        }
+        self.len += 1;
+        unsafe { self.insert_fixup(new_ptr) };
+        None
+    }
+
+    /// Removes the key/value pair for `key`, if it is present, returning the
+    /// removed value.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let z = self.find_ptr(key);
+        if z == null_mut() {
+            return None;
+        }
+        Some(unsafe { self.remove_node(z) })
+    }
+
+    /// Returns a cursor focused on the node with the smallest key, or `None`
+    /// if the tree is empty.
+    pub fn cursor_first(&self) -> Option<Cursor<K, V>> {
+        let root = self.root_ptr();
+        if root == null_mut() {
+            None
+        } else {
+            Some(Cursor::new(unsafe { leftmost(root) }))
+        }
+    }
+
+    /// Returns a cursor focused on the node with the largest key, or `None`
+    /// if the tree is empty.
+    pub fn cursor_last(&self) -> Option<Cursor<K, V>> {
+        let root = self.root_ptr();
+        if root == null_mut() {
+            None
+        } else {
+            Some(Cursor::new(unsafe { rightmost(root) }))
+        }
+    }
+
+    fn root_ptr(&self) -> *mut RbNode<K, V> {
+        match self.root {
+            Some(ref node) => &**node as *const _ as *mut _,
+            None => null_mut()
+        }
+    }
+
+    fn find_ptr(&self, key: &K) -> *mut RbNode<K, V> {
+        let mut current = self.root_ptr();
+        unsafe {
+            while current != null_mut() {
+                current = match key.cmp(&(*current).key) {
+                    Ordering::Equal => return current,
+                    Ordering::Less => (*current).left_ptr(),
+                    Ordering::Greater => (*current).right_ptr()
+                };
+            }
+        }
+        null_mut()
+    }
+
+    /// Replaces the subtree rooted at `u` with `v`, fixing up `v`'s parent
+    /// pointer, and returns the node that was detached from `u`'s slot.
+    unsafe fn transplant(&mut self, u: *mut RbNode<K, V>, mut v: Child<K, V>) -> Box<RbNode<K, V>> {
+        let u_parent = (*u).parent;
+        if let Some(ref mut node) = v {
+            node.parent = u_parent;
+        }
+        let slot = self.slot_mut(u_parent, u);
+        mem::replace(slot, v).expect("transplant: u missing from its slot")
+    }
+
+    /// Like `transplant`, but for a child that is already owned rather than
+    /// stored as an `Option`.
+    unsafe fn transplant_box(&mut self, u: *mut RbNode<K, V>, mut v: Box<RbNode<K, V>>) -> Box<RbNode<K, V>> {
+        let u_parent = (*u).parent;
+        v.parent = u_parent;
+        let slot = self.slot_mut(u_parent, u);
+        mem::replace(slot, Some(v)).expect("transplant_box: u missing from its slot")
+    }
+
+    /// Gets the slot (root, or a parent's left/right child field) that
+    /// currently owns `node`.
+    unsafe fn slot_mut(&mut self, parent: *mut RbNode<K, V>, node: *mut RbNode<K, V>) -> &mut Child<K, V> {
+        if parent == null_mut() {
+            &mut self.root
+        } else if (*parent).left_ptr() == node {
+            &mut (*parent).children.0
+        } else {
+            &mut (*parent).children.1
+        }
+    }
+
+    fn rotate_left(&mut self, x: *mut RbNode<K, V>) {
+        unsafe {
+            let mut y_box = (*x).children.1.take().expect("rotate_left requires a right child");
+            let y: *mut RbNode<K, V> = &mut *y_box;
+
+            let mut beta = y_box.children.0.take();
+            if let Some(ref mut node) = beta {
+                node.parent = x;
+            }
+            (*x).children.1 = beta;
+
+            let x_parent = (*x).parent;
+            let x_was_left = x_parent != null_mut() && (*x_parent).left_ptr() == x;
+            y_box.parent = x_parent;
+
+            let mut x_box = if x_parent == null_mut() {
+                self.root.take().expect("rotate_left: x missing from root slot")
+            } else if x_was_left {
+                (*x_parent).children.0.take().expect("rotate_left: x missing from parent")
+            } else {
+                (*x_parent).children.1.take().expect("rotate_left: x missing from parent")
+            };
+            x_box.parent = y;
+            y_box.children.0 = Some(x_box);
+
+            if x_parent == null_mut() {
+                self.root = Some(y_box);
+            } else if x_was_left {
+                (*x_parent).children.0 = Some(y_box);
+            } else {
+                (*x_parent).children.1 = Some(y_box);
+            }
+        }
+    }
+
+    fn rotate_right(&mut self, x: *mut RbNode<K, V>) {
+        unsafe {
+            let mut y_box = (*x).children.0.take().expect("rotate_right requires a left child");
+            let y: *mut RbNode<K, V> = &mut *y_box;
+
+            let mut beta = y_box.children.1.take();
+            if let Some(ref mut node) = beta {
+                node.parent = x;
+            }
+            (*x).children.0 = beta;
+
+            let x_parent = (*x).parent;
+            let x_was_left = x_parent != null_mut() && (*x_parent).left_ptr() == x;
+            y_box.parent = x_parent;
+
+            let mut x_box = if x_parent == null_mut() {
+                self.root.take().expect("rotate_right: x missing from root slot")
+            } else if x_was_left {
+                (*x_parent).children.0.take().expect("rotate_right: x missing from parent")
+            } else {
+                (*x_parent).children.1.take().expect("rotate_right: x missing from parent")
+            };
+            x_box.parent = y;
+            y_box.children.1 = Some(x_box);
+
+            if x_parent == null_mut() {
+                self.root = Some(y_box);
+            } else if x_was_left {
+                (*x_parent).children.0 = Some(y_box);
+            } else {
+                (*x_parent).children.1 = Some(y_box);
+            }
+        }
+    }
+
+    unsafe fn insert_fixup(&mut self, mut node: *mut RbNode<K, V>) {
+        while is_red((*node).parent) {
+            let parent = (*node).parent;
+            let grandparent = (*parent).parent;
+            if parent == (*grandparent).left_ptr() {
+                let uncle = (*grandparent).right_ptr();
+                if is_red(uncle) {
+                    (*parent).color = Color::Black;
+                    (*uncle).color = Color::Black;
+                    (*grandparent).color = Color::Red;
+                    node = grandparent;
+                } else {
+                    if node == (*parent).right_ptr() {
+                        node = parent;
+                        self.rotate_left(node);
+                    }
+                    let parent = (*node).parent;
+                    let grandparent = (*parent).parent;
+                    (*parent).color = Color::Black;
+                    (*grandparent).color = Color::Red;
+                    self.rotate_right(grandparent);
+                }
+            } else {
+                let uncle = (*grandparent).left_ptr();
+                if is_red(uncle) {
+                    (*parent).color = Color::Black;
+                    (*uncle).color = Color::Black;
+                    (*grandparent).color = Color::Red;
+                    node = grandparent;
+                } else {
+                    if node == (*parent).left_ptr() {
+                        node = parent;
+                        self.rotate_right(node);
+                    }
+                    let parent = (*node).parent;
+                    let grandparent = (*parent).parent;
+                    (*parent).color = Color::Black;
+                    (*grandparent).color = Color::Red;
+                    self.rotate_left(grandparent);
+                }
+            }
+        }
+        if let Some(ref mut root) = self.root {
+            root.color = Color::Black;
+        }
+    }
+
+    unsafe fn remove_node(&mut self, z: *mut RbNode<K, V>) -> V {
+        let mut y_original_color = (*z).color;
+        let x: *mut RbNode<K, V>;
+        let x_parent: *mut RbNode<K, V>;
+        let removed: Box<RbNode<K, V>>;
+
+        if (*z).children.0.is_none() {
+            x = (*z).right_ptr();
+            x_parent = (*z).parent;
+            removed = self.transplant(z, (*z).children.1.take());
+        } else if (*z).children.1.is_none() {
+            x = (*z).left_ptr();
+            x_parent = (*z).parent;
+            removed = self.transplant(z, (*z).children.0.take());
+        } else {
+            let y = leftmost((*z).right_ptr());
+            y_original_color = (*y).color;
+            x = (*y).right_ptr();
+
+            let mut y_box = if (*y).parent == z {
+                x_parent = y;
+                (*z).children.1.take().expect("y should be z's right child")
+            } else {
+                x_parent = (*y).parent;
+                let y_right = (*y).children.1.take();
+                let mut y_box = self.transplant(y, y_right);
+                let mut z_right = (*z).children.1.take();
+                if let Some(ref mut node) = z_right {
+                    node.parent = y;
+                }
+                y_box.children.1 = z_right;
+                y_box
+            };
+
+            let mut z_left = (*z).children.0.take();
+            if let Some(ref mut node) = z_left {
+                node.parent = y;
+            }
+            y_box.children.0 = z_left;
+            y_box.color = (*z).color;
+
+            removed = self.transplant_box(z, y_box);
+        }
+
+        self.len -= 1;
+        if y_original_color == Color::Black {
+            self.remove_fixup(x, x_parent);
+        }
+        removed.value
+    }
+
+    unsafe fn remove_fixup(&mut self, mut x: *mut RbNode<K, V>, mut x_parent: *mut RbNode<K, V>) {
+        while x_parent != null_mut() && !is_red(x) {
+            if x == (*x_parent).left_ptr() {
+                let mut w = (*x_parent).right_ptr();
+                if is_red(w) {
+                    (*w).color = Color::Black;
+                    (*x_parent).color = Color::Red;
+                    self.rotate_left(x_parent);
+                    w = (*x_parent).right_ptr();
+                }
+                if !is_red((*w).left_ptr()) && !is_red((*w).right_ptr()) {
+                    (*w).color = Color::Red;
+                    x = x_parent;
+                    x_parent = (*x).parent;
+                } else {
+                    if !is_red((*w).right_ptr()) {
+                        let w_left = (*w).left_ptr();
+                        if w_left != null_mut() {
+                            (*w_left).color = Color::Black;
+                        }
+                        (*w).color = Color::Red;
+                        self.rotate_right(w);
+                        w = (*x_parent).right_ptr();
+                    }
+                    (*w).color = (*x_parent).color;
+                    (*x_parent).color = Color::Black;
+                    let w_right = (*w).right_ptr();
+                    if w_right != null_mut() {
+                        (*w_right).color = Color::Black;
+                    }
+                    self.rotate_left(x_parent);
+                    x = self.root_ptr();
+                    x_parent = null_mut();
+                }
+            } else {
+                let mut w = (*x_parent).left_ptr();
+                if is_red(w) {
+                    (*w).color = Color::Black;
+                    (*x_parent).color = Color::Red;
+                    self.rotate_right(x_parent);
+                    w = (*x_parent).left_ptr();
+                }
+                if !is_red((*w).left_ptr()) && !is_red((*w).right_ptr()) {
+                    (*w).color = Color::Red;
+                    x = x_parent;
+                    x_parent = (*x).parent;
+                } else {
+                    if !is_red((*w).left_ptr()) {
+                        let w_right = (*w).right_ptr();
+                        if w_right != null_mut() {
+                            (*w_right).color = Color::Black;
+                        }
+                        (*w).color = Color::Red;
+                        self.rotate_left(w);
+                        w = (*x_parent).left_ptr();
+                    }
+                    (*w).color = (*x_parent).color;
+                    (*x_parent).color = Color::Black;
+                    let w_left = (*w).left_ptr();
+                    if w_left != null_mut() {
+                        (*w_left).color = Color::Black;
+                    }
+                    self.rotate_right(x_parent);
+                    x = self.root_ptr();
+                    x_parent = null_mut();
+                }
+            }
+        }
+        if x != null_mut() {
+            (*x).color = Color::Black;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RedBlackTree, Color};
+    use super::node::is_red;
+    use std::ptr::null_mut;
+
+    #[test]
+    fn insert_get_roundtrip() {
+        let mut tree = RedBlackTree::new();
+        assert_eq!(tree.insert(5, "five"), None);
+        assert_eq!(tree.insert(2, "two"), None);
+        assert_eq!(tree.insert(8, "eight"), None);
+        assert_eq!(tree.len(), 3);
+        assert_eq!(tree.get(&5), Some(&"five"));
+        assert_eq!(tree.get(&2), Some(&"two"));
+        assert_eq!(tree.get(&8), Some(&"eight"));
+        assert_eq!(tree.get(&9), None);
+    }
+
+    #[test]
+    fn insert_replaces_existing_value() {
+        let mut tree = RedBlackTree::new();
+        tree.insert(1, "a");
+        assert_eq!(tree.insert(1, "b"), Some("a"));
+        assert_eq!(tree.len(), 1);
+        assert_eq!(tree.get(&1), Some(&"b"));
+    }
+
+    #[test]
+    fn remove_shrinks_tree_and_keeps_the_rest() {
+        let mut tree = RedBlackTree::new();
+        for key in 0..10 {
+            tree.insert(key, key * 10);
+        }
+        assert_eq!(tree.remove(&3), Some(30));
+        assert_eq!(tree.remove(&3), None);
+        assert_eq!(tree.len(), 9);
+        assert_eq!(tree.get(&3), None);
+        for key in 0..10 {
+            if key != 3 {
+                assert_eq!(tree.get(&key), Some(&(key * 10)));
+            }
+        }
+    }
+
+    #[test]
+    fn cursor_walks_keys_in_sorted_order() {
+        let mut tree = RedBlackTree::new();
+        for key in [5, 1, 9, 3, 7].iter() {
+            tree.insert(*key, ());
+        }
+        let mut keys = Vec::new();
+        let mut cursor = tree.cursor_first();
+        while let Some(c) = cursor {
+            keys.push(*c.key());
+            cursor = c.next().ok();
+        }
+        assert_eq!(keys, vec![1, 3, 5, 7, 9]);
+    }
+
+    /// A tiny linear congruential generator, used instead of a `rand`
+    /// dependency to get deterministic pseudo-random keys for the stress
+    /// test below.
+    fn lcg(state: &mut u64) -> u64 {
+        *state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        *state
+    }
+
+    /// Checks that every red node has two black children and that every
+    /// root-to-leaf path has the same black height, returning that height.
+    fn check_invariants<K: Ord, V>(tree: &RedBlackTree<K, V>) -> usize {
+        unsafe fn walk<K, V>(node: *mut super::node::RbNode<K, V>) -> usize {
+            if node == null_mut() {
+                return 1;
+            }
+            let left = (*node).left_ptr();
+            let right = (*node).right_ptr();
+            if (*node).color == Color::Red {
+                assert!(!is_red(left), "red node has a red left child");
+                assert!(!is_red(right), "red node has a red right child");
+            }
+            let left_height = walk(left);
+            let right_height = walk(right);
+            assert_eq!(left_height, right_height, "black height mismatch");
+            left_height + if (*node).color == Color::Black { 1 } else { 0 }
+        }
+        let root = tree.root_ptr();
+        unsafe {
+            assert!(!is_red(root), "root must be black");
+            walk(root)
+        }
+    }
+
+    #[test]
+    fn maintains_invariants_across_many_inserts_and_removes() {
+        let mut tree = RedBlackTree::new();
+        let mut present = Vec::new();
+        let mut state = 0xdead_beefu64;
+        for _ in 0..2000 {
+            let key = (lcg(&mut state) % 500) as i64;
+            if lcg(&mut state) % 3 == 0 && !present.is_empty() {
+                let idx = (lcg(&mut state) as usize) % present.len();
+                let removed: i64 = present.swap_remove(idx);
+                tree.remove(&removed);
+            } else {
+                if tree.insert(key, key).is_none() {
+                    present.push(key);
+                }
+            }
+            check_invariants(&tree);
+        }
+        assert_eq!(tree.len(), present.len());
+        for key in &present {
+            assert_eq!(tree.get(key), Some(key));
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use std::ptr::null_mut;
+
+/// The color of a node in a `RedBlackTree`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Color {
+    Red,
+    Black
+}
+
+pub(crate) type Child<K, V> = Option<Box<RbNode<K, V>>>;
+pub(crate) type Children<K, V> = (Child<K, V>, Child<K, V>);
+
+/// A node in a `RedBlackTree`. Mirrors `BinaryNode`'s parent-pointer and
+/// two-child layout, with an extra color bit used by the fixup routines.
+#[derive(Debug)]
+pub(crate) struct RbNode<K, V> {
+    pub(crate) parent: *mut RbNode<K, V>,
+    pub(crate) children: Children<K, V>,
+    pub(crate) color: Color,
+    pub(crate) key: K,
+    pub(crate) value: V
+}
+
+impl <K, V> RbNode<K, V> {
+    /// Constructs a new, red, childless node.
+    pub(crate) fn new(key: K, value: V) -> Self {
+        RbNode {
+            parent: null_mut(),
+            children: (None, None),
+            color: Color::Red,
+            key: key,
+            value: value
+        }
+    }
+
+    /// Gets a raw pointer to the left child, or a null pointer if there is none.
+    pub(crate) fn left_ptr(&self) -> *mut RbNode<K, V> {
+        match self.children.0 {
+            Some(ref child) => &**child as *const _ as *mut _,
+            None => null_mut()
+        }
+    }
+
+    /// Gets a raw pointer to the right child, or a null pointer if there is none.
+    pub(crate) fn right_ptr(&self) -> *mut RbNode<K, V> {
+        match self.children.1 {
+            Some(ref child) => &**child as *const _ as *mut _,
+            None => null_mut()
+        }
+    }
+}
+
+/// A null node is considered black, as in the usual sentinel-based
+/// presentation of red-black trees.
+pub(crate) unsafe fn is_red<K, V>(node: *mut RbNode<K, V>) -> bool {
+    node != null_mut() && (*node).color == Color::Red
+}
+
+/// Walks to the leftmost descendant of `node`, or `node` itself if it has no
+/// left child.
+pub(crate) unsafe fn leftmost<K, V>(mut node: *mut RbNode<K, V>) -> *mut RbNode<K, V> {
+    loop {
+        let left = (*node).left_ptr();
+        if left == null_mut() {
+            return node;
+        }
+        node = left;
+    }
+}
+
+/// Walks to the rightmost descendant of `node`, or `node` itself if it has no
+/// right child.
+pub(crate) unsafe fn rightmost<K, V>(mut node: *mut RbNode<K, V>) -> *mut RbNode<K, V> {
+    loop {
+        let right = (*node).right_ptr();
+        if right == null_mut() {
+            return node;
+        }
+        node = right;
+    }
+}
+
+/// Finds the in-order successor of `node`, or a null pointer if `node` is the
+/// last node in order.
+pub(crate) unsafe fn successor<K, V>(node: *mut RbNode<K, V>) -> *mut RbNode<K, V> {
+    let right = (*node).right_ptr();
+    if right != null_mut() {
+        return leftmost(right);
+    }
+    let mut child = node;
+    loop {
+        let parent = (*child).parent;
+        if parent == null_mut() {
+            return null_mut();
+        }
+        if (*parent).left_ptr() == child {
+            return parent;
+        }
+        child = parent;
+    }
+}
+
+/// Finds the in-order predecessor of `node`, or a null pointer if `node` is
+/// the first node in order.
+pub(crate) unsafe fn predecessor<K, V>(node: *mut RbNode<K, V>) -> *mut RbNode<K, V> {
+    let left = (*node).left_ptr();
+    if left != null_mut() {
+        return rightmost(left);
+    }
+    let mut child = node;
+    loop {
+        let parent = (*child).parent;
+        if parent == null_mut() {
+            return null_mut();
+        }
+        if (*parent).right_ptr() == child {
+            return parent;
+        }
+        child = parent;
+    }
+}
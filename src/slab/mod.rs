@@ -0,0 +1,189 @@
+mod view;
+
+use std::mem;
+
+pub use self::view::{View, ViewMut};
+
+/// A handle to a node in a `BinaryTree`'s backing storage.
+///
+/// Unlike `binary::BinaryTree`, which hands out raw `*mut BinaryNode<T>`
+/// pointers, handles here are plain indices: copyable, comparable, and
+/// unaffected by the backing `Vec` being reallocated as the tree grows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeId(usize);
+
+/// The direction in the tree to go.
+/// Left refers to the first element.
+/// Right refers to the second element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dir {
+    Left,
+    Right
+}
+
+/// A single slot in a `BinaryTree`'s backing storage: a value, plus the
+/// handles of its parent and children.
+#[derive(Debug)]
+struct Slot<T> {
+    parent: Option<NodeId>,
+    children: (Option<NodeId>, Option<NodeId>),
+    value: T
+}
+
+/// A tree where each node has 0, 1, or 2 children, backed by a single `Vec`
+/// of slots rather than one heap allocation per node.
+///
+/// This trades `binary::BinaryTree`'s pointer-chasing (and the O(height)
+/// walk `into_mut` pays to re-prove it is safe to hand out a mutable
+/// reference) for plain index arithmetic: a `ViewMut` is just a borrow of
+/// this `Vec` keyed by `NodeId`.
+#[derive(Debug)]
+pub struct BinaryTree<T> {
+    slots: Vec<Slot<T>>,
+    root: NodeId
+}
+
+impl <T> BinaryTree<T> {
+    /// Constructs a new tree with a single root node holding `value`.
+    pub fn new(value: T) -> Self {
+        BinaryTree {
+            slots: vec![Slot {
+                parent: None,
+                children: (None, None),
+                value: value
+            }],
+            root: NodeId(0)
+        }
+    }
+
+    /// Gets the handle of the tree's root node.
+    pub fn root(&self) -> NodeId {
+        self.root
+    }
+
+    /// Gets a reference to the value stored at `id`.
+    pub fn value(&self, id: NodeId) -> &T {
+        &self.slots[id.0].value
+    }
+
+    /// Gets a mutable reference to the value stored at `id`.
+    pub fn value_mut(&mut self, id: NodeId) -> &mut T {
+        &mut self.slots[id.0].value
+    }
+
+    /// Gets the handles of the left/right children of `id`.
+    pub fn children(&self, id: NodeId) -> (Option<NodeId>, Option<NodeId>) {
+        self.slots[id.0].children
+    }
+
+    /// Gets the handle of the parent of `id`, if any.
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.slots[id.0].parent
+    }
+
+    /// Replaces the left/right child (if any) of `id` with a freshly
+    /// allocated node holding `value`, returning the new node's handle
+    /// along with the handle of the child it displaced, if any.
+    ///
+    /// A displaced handle (and the subtree hanging off it) is not reclaimed
+    /// from `slots` — it is simply detached from `id`. It is still a valid
+    /// handle into this tree (e.g. for `view_at`/`view_mut_at`), just no
+    /// longer reachable from the root; its `parent` still names `id`.
+    pub fn add_child(&mut self, id: NodeId, dir: Dir, value: T) -> (NodeId, Option<NodeId>) {
+        let child_id = NodeId(self.slots.len());
+        self.slots.push(Slot {
+            parent: Some(id),
+            children: (None, None),
+            value: value
+        });
+        let slot = &mut self.slots[id.0];
+        let displaced = match dir {
+            Dir::Left  => mem::replace(&mut slot.children.0, Some(child_id)),
+            Dir::Right => mem::replace(&mut slot.children.1, Some(child_id))
+        };
+        (child_id, displaced)
+    }
+
+    /// Constructs an immutable view of the tree, focused on the root.
+    pub fn view(&self) -> View<T> {
+        View::new(self, self.root)
+    }
+
+    /// Constructs an immutable view of the tree, focused on `id`.
+    pub fn view_at(&self, id: NodeId) -> View<T> {
+        View::new(self, id)
+    }
+
+    /// Constructs a mutable view of the tree, focused on the root.
+    pub fn view_mut(&mut self) -> ViewMut<T> {
+        let root = self.root;
+        ViewMut::new(self, root)
+    }
+
+    /// Constructs a mutable view of the tree, focused on `id`.
+    ///
+    /// Since `id` is already a handle into the backing storage, this is
+    /// O(1) — there is no parent-walk to re-prove aliasing safety, unlike
+    /// `binary::BinaryView::into_mut`.
+    pub fn view_mut_at(&mut self, id: NodeId) -> ViewMut<T> {
+        ViewMut::new(self, id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BinaryTree, Dir};
+
+    #[test]
+    fn add_child_links_parent_and_child() {
+        let mut tree = BinaryTree::new(1);
+        let root = tree.root();
+        let (left, displaced) = tree.add_child(root, Dir::Left, 2);
+        assert_eq!(displaced, None);
+        assert_eq!(*tree.value(left), 2);
+        assert_eq!(tree.parent(left), Some(root));
+        assert_eq!(tree.children(root), (Some(left), None));
+    }
+
+    #[test]
+    fn add_child_returns_the_previously_displaced_child() {
+        let mut tree = BinaryTree::new(1);
+        let root = tree.root();
+        let (first, _) = tree.add_child(root, Dir::Left, 2);
+        let (second, displaced) = tree.add_child(root, Dir::Left, 3);
+        assert_eq!(displaced, Some(first));
+        assert_eq!(tree.children(root), (Some(second), None));
+        // The displaced node is detached from the root, but its handle (and
+        // value) are still valid.
+        assert_eq!(*tree.value(first), 2);
+        assert_eq!(tree.parent(first), Some(root));
+    }
+
+    #[test]
+    fn view_climbs_and_descends() {
+        let mut tree = BinaryTree::new(1);
+        let root = tree.root();
+        let (left, _) = tree.add_child(root, Dir::Left, 2);
+        let (right, _) = tree.add_child(root, Dir::Right, 3);
+
+        let view = tree.view().descend(Dir::Left).ok().expect("root has a left child");
+        assert_eq!(view.id(), left);
+        assert_eq!(*view.value(), 2);
+        let view = view.climb().ok().expect("left child has a parent");
+        assert_eq!(view.id(), root);
+
+        let view = view.descend(Dir::Right).ok().expect("root has a right child");
+        assert_eq!(view.id(), right);
+        assert!(view.descend(Dir::Left).is_err());
+    }
+
+    #[test]
+    fn view_mut_can_update_and_add_children() {
+        let mut tree = BinaryTree::new(1);
+        let mut view = tree.view_mut();
+        *view.value_mut() = 10;
+        let (left, _) = view.add_child(Dir::Left, 20);
+        assert_eq!(*tree.value(left), 20);
+        assert_eq!(*tree.value(tree.root()), 10);
+    }
+}
@@ -0,0 +1,123 @@
+use super::{BinaryTree, Dir, NodeId};
+
+/// Immutable view into a `BinaryTree`, focused on a single node.
+pub struct View<'tree, T: 'tree> {
+    tree: &'tree BinaryTree<T>,
+    node: NodeId
+}
+
+/// Mutable view into a `BinaryTree`, focused on a single node.
+pub struct ViewMut<'tree, T: 'tree> {
+    tree: &'tree mut BinaryTree<T>,
+    node: NodeId
+}
+
+impl <'tree, T: 'tree> View<'tree, T> {
+    pub(crate) fn new(tree: &'tree BinaryTree<T>, node: NodeId) -> Self {
+        View { tree: tree, node: node }
+    }
+
+    /// Gets the handle of the node the view is focused on.
+    pub fn id(&self) -> NodeId {
+        self.node
+    }
+
+    /// Gets the value of the node the view is focused on.
+    pub fn value(&self) -> &T {
+        self.tree.value(self.node)
+    }
+
+    /// Attempts to climb up the tree.
+    ///
+    /// If the view is at the root (and thus had no parent), an `Err` with
+    /// the view in its original place is returned.
+    pub fn climb(mut self) -> Result<Self, Self> {
+        match self.tree.parent(self.node) {
+            Some(parent) => {
+                self.node = parent;
+                Ok(self)
+            },
+            None => Err(self)
+        }
+    }
+
+    /// Attempts to descend down the tree in some direction.
+    ///
+    /// If the node the view is focused on did not have a child in that
+    /// direction, an `Err` with the view in its original place is returned.
+    pub fn descend(mut self, dir: Dir) -> Result<Self, Self> {
+        let (left, right) = self.tree.children(self.node);
+        let child = match dir {
+            Dir::Left  => left,
+            Dir::Right => right
+        };
+        match child {
+            Some(child) => {
+                self.node = child;
+                Ok(self)
+            },
+            None => Err(self)
+        }
+    }
+}
+
+impl <'tree, T: 'tree> ViewMut<'tree, T> {
+    pub(crate) fn new(tree: &'tree mut BinaryTree<T>, node: NodeId) -> Self {
+        ViewMut { tree: tree, node: node }
+    }
+
+    /// Gets the handle of the node the view is focused on.
+    pub fn id(&self) -> NodeId {
+        self.node
+    }
+
+    /// Gets the value of the node the view is focused on.
+    pub fn value(&self) -> &T {
+        self.tree.value(self.node)
+    }
+
+    /// Gets a mutable reference to the value of the node the view is
+    /// focused on.
+    pub fn value_mut(&mut self) -> &mut T {
+        self.tree.value_mut(self.node)
+    }
+
+    /// Replaces the left/right child (if any) of the focused node with a
+    /// freshly allocated node holding `value`, returning the new node's
+    /// handle along with the handle of the child it displaced, if any.
+    pub fn add_child(&mut self, dir: Dir, value: T) -> (NodeId, Option<NodeId>) {
+        let node = self.node;
+        self.tree.add_child(node, dir, value)
+    }
+
+    /// Attempts to climb up the tree.
+    ///
+    /// Behaves like the method on `View`; please see it for more details.
+    pub fn climb(mut self) -> Result<Self, Self> {
+        match self.tree.parent(self.node) {
+            Some(parent) => {
+                self.node = parent;
+                Ok(self)
+            },
+            None => Err(self)
+        }
+    }
+
+    /// Attempts to descend down the tree in some direction.
+    ///
+    /// Behaves like the method on `View`; please see it for more details.
+    pub fn descend(mut self, dir: Dir) -> Result<Self, Self> {
+        let (left, right) = self.tree.children(self.node);
+        let child = match dir {
+            Dir::Left  => left,
+            Dir::Right => right
+        };
+        match child {
+            Some(child) => {
+                self.node = child;
+                Ok(self)
+            },
+            None => Err(self)
+        }
+    }
+}